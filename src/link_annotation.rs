@@ -87,6 +87,40 @@ impl Into<Object> for BorderArray {
     }
 }
 
+impl BorderArray {
+    /// Converts this border into a border style dictionary (PDF 32000-1:2008 Table 166), the
+    /// `/BS` form markup annotations expect, as opposed to the legacy `/Border` array that
+    /// [`LinkAnnotation`] uses.
+    ///
+    /// The corner-radius components of the `/Border` array have no equivalent in `/BS`, so only
+    /// the width (and, for `Dashed`, the dash array) carry over.
+    pub fn into_border_style_dict(self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("Type", Object::Name("Border".as_bytes().to_vec()));
+        match self {
+            BorderArray::Solid(arr) => {
+                dict.set("W", Object::Real(arr[2].into()));
+                dict.set("S", Object::Name("S".as_bytes().to_vec()));
+            }
+            BorderArray::Dashed(arr, phase) => {
+                dict.set("W", Object::Real(arr[2].into()));
+                dict.set("S", Object::Name("D".as_bytes().to_vec()));
+                dict.set(
+                    "D",
+                    Object::Array(
+                        phase
+                            .dash_array
+                            .into_iter()
+                            .map(|x| Object::Real(x.into()))
+                            .collect(),
+                    ),
+                );
+            }
+        }
+        dict
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DashPhase {
     pub dash_array: Vec<f32>,
@@ -155,12 +189,249 @@ pub enum Destination {
         top: Option<f32>,
         zoom: Option<f32>,
     },
+    /// Display `page` with its contents magnified just enough to fit the entire page within the
+    /// window both horizontally and vertically.
+    Fit { page: PdfPageIndex },
+    /// Display `page` with the vertical coordinate `top` positioned at the top edge of the window
+    /// and the contents of the page magnified just enough to fit the entire width of the page
+    /// within the window.
+    FitH {
+        page: PdfPageIndex,
+        top: Option<f32>,
+    },
+    /// Display `page` with the horizontal coordinate `left` positioned at the left edge of the
+    /// window and the contents of the page magnified just enough to fit the entire height of the
+    /// page within the window.
+    FitV {
+        page: PdfPageIndex,
+        left: Option<f32>,
+    },
+    /// Display `page` with its contents magnified just enough to fit the rectangle specified by
+    /// `left`, `bottom`, `right`, and `top` entirely within the window.
+    FitR {
+        page: PdfPageIndex,
+        left: f32,
+        bottom: f32,
+        right: f32,
+        top: f32,
+    },
+    /// Display `page` with its contents magnified just enough to fit its bounding box entirely
+    /// within the window.
+    FitB { page: PdfPageIndex },
+    /// Display `page` with the vertical coordinate `top` positioned at the top edge of the window
+    /// and the contents of the page magnified just enough to fit the entire width of its bounding
+    /// box within the window.
+    FitBH {
+        page: PdfPageIndex,
+        top: Option<f32>,
+    },
+    /// Display `page` with the horizontal coordinate `left` positioned at the left edge of the
+    /// window and the contents of the page magnified just enough to fit the entire height of its
+    /// bounding box within the window.
+    FitBV {
+        page: PdfPageIndex,
+        left: Option<f32>,
+    },
+    /// Targets a document-level named destination registered in the catalog's `/Names /Dests`
+    /// name tree (see [`NamedDestinations`]), rather than an explicit page and fit mode.
+    Named(String),
+}
+
+impl Destination {
+    fn page(&self) -> PdfPageIndex {
+        match self {
+            Destination::XYZ { page, .. } => *page,
+            Destination::Fit { page } => *page,
+            Destination::FitH { page, .. } => *page,
+            Destination::FitV { page, .. } => *page,
+            Destination::FitR { page, .. } => *page,
+            Destination::FitB { page } => *page,
+            Destination::FitBH { page, .. } => *page,
+            Destination::FitBV { page, .. } => *page,
+            Destination::Named(_) => {
+                unreachable!("named destinations have no page to resolve")
+            }
+        }
+    }
+
+    fn into_object(self, ctx: &AnnotationContext) -> Object {
+        if let Destination::Named(name) = self {
+            return Object::String(name.into_bytes(), lopdf::StringFormat::Literal);
+        }
+
+        let page_ref = Object::Reference(
+            ctx.page_id_to_obj
+                .get(&self.page().0)
+                .expect("page index should be valid object")
+                .to_owned(),
+        );
+        self.into_object_with_page(page_ref)
+    }
+
+    /// Encodes the destination using the page's integer page number rather than an indirect
+    /// object reference, as required when the destination points into another PDF file whose
+    /// object numbers are not known to this document.
+    fn into_object_external(self) -> Object {
+        if let Destination::Named(name) = self {
+            return Object::String(name.into_bytes(), lopdf::StringFormat::Literal);
+        }
+
+        let page_number = Object::Integer(self.page().0 as i64);
+        self.into_object_with_page(page_number)
+    }
+
+    fn into_object_with_page(self, page_ref: Object) -> Object {
+        match self {
+            Destination::XYZ {
+                left, top, zoom, ..
+            } => Object::Array(vec![
+                page_ref,
+                "XYZ".into(),
+                left.map(Object::Real).unwrap_or(Object::Null),
+                top.map(Object::Real).unwrap_or(Object::Null),
+                zoom.map(Object::Real).unwrap_or(Object::Null),
+            ]),
+            Destination::Fit { .. } => Object::Array(vec![page_ref, "Fit".into()]),
+            Destination::FitH { top, .. } => Object::Array(vec![
+                page_ref,
+                "FitH".into(),
+                top.map(Object::Real).unwrap_or(Object::Null),
+            ]),
+            Destination::FitV { left, .. } => Object::Array(vec![
+                page_ref,
+                "FitV".into(),
+                left.map(Object::Real).unwrap_or(Object::Null),
+            ]),
+            Destination::FitR {
+                left,
+                bottom,
+                right,
+                top,
+                ..
+            } => Object::Array(vec![
+                page_ref,
+                "FitR".into(),
+                Object::Real(left),
+                Object::Real(bottom),
+                Object::Real(right),
+                Object::Real(top),
+            ]),
+            Destination::FitB { .. } => Object::Array(vec![page_ref, "FitB".into()]),
+            Destination::FitBH { top, .. } => Object::Array(vec![
+                page_ref,
+                "FitBH".into(),
+                top.map(Object::Real).unwrap_or(Object::Null),
+            ]),
+            Destination::FitBV { left, .. } => Object::Array(vec![
+                page_ref,
+                "FitBV".into(),
+                left.map(Object::Real).unwrap_or(Object::Null),
+            ]),
+            Destination::Named(_) => {
+                unreachable!("named destinations are encoded before a page reference is resolved")
+            }
+        }
+    }
+}
+
+/// A document-level registry of named destinations, emitted in the catalog as `/Names <<
+/// /Dests <name tree> >>`.
+///
+/// Registering a destination under a stable name lets callers point many [`Actions::GoTo`] links
+/// at it without recomputing page coordinates, and decouples link authoring from page-object
+/// allocation order.
+#[derive(Default, Debug, Clone)]
+pub struct NamedDestinations {
+    destinations: std::collections::BTreeMap<String, Destination>,
+}
+
+impl NamedDestinations {
+    /// Creates a new, empty named destination registry.
+    pub fn new() -> Self {
+        Self {
+            destinations: std::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Registers `destination` under `name`, overwriting any existing destination with that
+    /// name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `destination` is itself a [`Destination::Named`] — the name tree must map to
+    /// explicit destinations, not to other names.
+    pub fn insert(&mut self, name: String, destination: Destination) {
+        assert!(
+            !matches!(destination, Destination::Named(_)),
+            "named destinations must target an explicit destination, not another name"
+        );
+        self.destinations.insert(name, destination);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.destinations.is_empty()
+    }
+
+    /// Builds the `/Dests` name tree for the catalog's `/Names` entry.
+    ///
+    /// Since `destinations` is a `BTreeMap`, iteration already yields keys in the lexical order
+    /// the PDF name-tree invariant requires, so this emits a single `/Kids`-less leaf node:
+    /// `/Names [ (name1) dest1 (name2) dest2 ... ]`.
+    pub fn into_name_tree(self, ctx: &AnnotationContext) -> Object {
+        let names = self
+            .destinations
+            .into_iter()
+            .flat_map(|(name, destination)| {
+                vec![
+                    Object::String(name.into_bytes(), lopdf::StringFormat::Literal),
+                    destination.into_object(ctx),
+                ]
+            })
+            .collect();
+
+        let mut dict = Dictionary::new();
+        dict.set("Names", Object::Array(names));
+        Object::Dictionary(dict)
+    }
+}
+
+/// One of the four standard page-navigation verbs understood by PDF readers.
+#[derive(Debug, Clone, Copy)]
+pub enum NamedAction {
+    NextPage,
+    PrevPage,
+    FirstPage,
+    LastPage,
+}
+
+impl Into<Object> for NamedAction {
+    fn into(self) -> Object {
+        let name = match self {
+            NamedAction::NextPage => "NextPage",
+            NamedAction::PrevPage => "PrevPage",
+            NamedAction::FirstPage => "FirstPage",
+            NamedAction::LastPage => "LastPage",
+        };
+        Object::Name(name.as_bytes().to_vec())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Actions {
     GoTo(Destination),
     URI(String),
+    /// Jumps to a `destination` within another PDF `file`.
+    GoToR {
+        file: String,
+        destination: Destination,
+    },
+    /// Executes one of the standard page-navigation verbs.
+    Named(NamedAction),
+    /// Launches an application or opens another file, optionally in a new window.
+    Launch {
+        file: String,
+        new_window: bool,
+    },
 }
 
 impl Actions {
@@ -172,33 +443,24 @@ impl Actions {
         Self::URI(uri)
     }
 
+    pub fn go_to_remote(file: String, destination: Destination) -> Self {
+        Self::GoToR { file, destination }
+    }
+
+    pub fn named(action: NamedAction) -> Self {
+        Self::Named(action)
+    }
+
+    pub fn launch(file: String, new_window: bool) -> Self {
+        Self::Launch { file, new_window }
+    }
+
     pub fn into_object(self, ctx: AnnotationContext) -> Object {
         let mut dict = Dictionary::new();
         match self {
             Self::GoTo(destination) => {
                 dict.set("S", Object::Name("GoTo".as_bytes().to_vec()));
-                dict.set(
-                    "D",
-                    match destination {
-                        Destination::XYZ {
-                            page,
-                            left,
-                            top,
-                            zoom,
-                        } => Object::Array(vec![
-                            Object::Reference(
-                                ctx.page_id_to_obj
-                                    .get(&page.0)
-                                    .expect("page index should be valid object")
-                                    .to_owned(),
-                            ),
-                            "XYZ".into(),
-                            left.map(Object::Real).unwrap_or(Object::Null),
-                            top.map(Object::Real).unwrap_or(Object::Null),
-                            zoom.map(Object::Real).unwrap_or(Object::Null),
-                        ]),
-                    },
-                );
+                dict.set("D", destination.into_object(&ctx));
             }
             Self::URI(uri) => {
                 dict.set("S", Object::Name("URI".as_bytes().to_vec()));
@@ -207,6 +469,26 @@ impl Actions {
                     Object::String(uri.into_bytes().to_vec(), lopdf::StringFormat::Literal),
                 );
             }
+            Self::GoToR { file, destination } => {
+                dict.set("S", Object::Name("GoToR".as_bytes().to_vec()));
+                dict.set(
+                    "F",
+                    Object::String(file.into_bytes().to_vec(), lopdf::StringFormat::Literal),
+                );
+                dict.set("D", destination.into_object_external());
+            }
+            Self::Named(action) => {
+                dict.set("S", Object::Name("Named".as_bytes().to_vec()));
+                dict.set::<&str, Object>("N", action.into());
+            }
+            Self::Launch { file, new_window } => {
+                dict.set("S", Object::Name("Launch".as_bytes().to_vec()));
+                dict.set(
+                    "F",
+                    Object::String(file.into_bytes().to_vec(), lopdf::StringFormat::Literal),
+                );
+                dict.set("NewWindow", Object::Boolean(new_window));
+            }
         }
         Object::Dictionary(dict)
     }
@@ -281,26 +563,33 @@ impl LinkAnnotationList {
             .insert(link_annotation_ref.name.clone(), link_annotation);
         link_annotation_ref
     }
-}
 
-impl From<LinkAnnotationList> for Dictionary {
-    fn from(_val: LinkAnnotationList) -> Self {
-        if _val.link_annotations.is_empty() {
-            return Dictionary::new();
-        }
+    /// Returns `true` if this list contains no annotations.
+    pub fn is_empty(&self) -> bool {
+        self.link_annotations.is_empty()
+    }
 
-        let mut dict = Dictionary::new();
-        dict.set("Type", Object::Name("Annot".as_bytes().to_vec()));
-        dict.set("Subtype", Object::Name("Link".as_bytes().to_vec()));
-        dict.set(
-            "Rect",
-            Object::Array(vec![
-                _val.link_annotations["PT0"].rect.ll.x.into(),
-                _val.link_annotations["PT0"].rect.ll.y.into(),
-                _val.link_annotations["PT0"].rect.ur.x.into(),
-                _val.link_annotations["PT0"].rect.ur.y.into(),
-            ]),
-        );
-        dict
+    /// Writes each [`LinkAnnotation`] in this list into `doc` as its own indirect object and
+    /// returns the resulting `/Annots` array of references, ready to be set on the owning page.
+    ///
+    /// Annotations are ordered by their `PT{index}` key (i.e. the order they were added in)
+    /// rather than `HashMap` iteration order, so the emitted array — and with it tab/reading
+    /// order — is reproducible across runs.
+    pub fn into_annots_array(self, doc: &mut lopdf::Document, ctx: AnnotationContext) -> Object {
+        let mut link_annotations: Vec<_> = self.link_annotations.into_iter().collect();
+        link_annotations.sort_by_key(|(name, _)| {
+            name.strip_prefix("PT")
+                .and_then(|index| index.parse::<usize>().ok())
+                .unwrap_or(usize::MAX)
+        });
+
+        let refs = link_annotations
+            .into_iter()
+            .map(|(_, link_annotation)| {
+                let obj_id = doc.add_object(link_annotation.into_object(ctx.clone()));
+                Object::Reference(obj_id)
+            })
+            .collect();
+        Object::Array(refs)
     }
 }
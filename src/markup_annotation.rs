@@ -0,0 +1,298 @@
+use crate::link_annotation::{AnnotationContext, BorderArray, ColorArray, LinkAnnotation};
+use crate::Rect;
+use lopdf::{self, Dictionary, Object, Stream};
+
+/// Fields shared by every markup annotation `Subtype` (`Text`, `Highlight`, `Underline`,
+/// `StrikeOut`, `Square`, and `Circle`).
+#[derive(Debug, Clone)]
+pub struct MarkupAnnotationCommon {
+    pub rect: Rect,
+    pub color: Option<ColorArray>,
+    pub contents: String,
+    /// The annotation's `/T` entry: the name of the user who added the annotation.
+    pub author: Option<String>,
+    /// The annotation's `/CreationDate` entry, formatted as a PDF date string (e.g.
+    /// `D:20231231235959`).
+    pub creation_date: Option<String>,
+    pub appearance_stream: Option<AppearanceStream>,
+}
+
+impl MarkupAnnotationCommon {
+    pub fn new(rect: Rect, contents: String) -> Self {
+        Self {
+            rect,
+            color: None,
+            contents,
+            author: None,
+            creation_date: None,
+            appearance_stream: None,
+        }
+    }
+
+    fn write_into(self, doc: &mut lopdf::Document, dict: &mut Dictionary) {
+        dict.set(
+            "Rect",
+            Object::Array(vec![
+                self.rect.ll.x.into(),
+                self.rect.ll.y.into(),
+                self.rect.ur.x.into(),
+                self.rect.ur.y.into(),
+            ]),
+        );
+        dict.set(
+            "Contents",
+            Object::String(self.contents.into_bytes(), lopdf::StringFormat::Literal),
+        );
+        if let Some(color) = self.color {
+            dict.set::<&str, Object>("C", color.into());
+        }
+        if let Some(author) = self.author {
+            dict.set(
+                "T",
+                Object::String(author.into_bytes(), lopdf::StringFormat::Literal),
+            );
+        }
+        if let Some(creation_date) = self.creation_date {
+            dict.set(
+                "CreationDate",
+                Object::String(creation_date.into_bytes(), lopdf::StringFormat::Literal),
+            );
+        }
+        if let Some(appearance_stream) = self.appearance_stream {
+            let xobject_ref = doc.add_object(appearance_stream.into_object());
+            let mut ap = Dictionary::new();
+            ap.set("N", Object::Reference(xobject_ref));
+            dict.set("AP", Object::Dictionary(ap));
+        }
+    }
+}
+
+/// A form XObject used as an annotation's `/AP` (appearance) normal appearance, for viewers that
+/// don't synthesize an appearance for the annotation automatically.
+#[derive(Debug, Clone)]
+pub struct AppearanceStream {
+    pub bbox: Rect,
+    pub content: Vec<u8>,
+}
+
+impl AppearanceStream {
+    fn into_object(self) -> Object {
+        let mut dict = Dictionary::new();
+        dict.set("Type", Object::Name("XObject".as_bytes().to_vec()));
+        dict.set("Subtype", Object::Name("Form".as_bytes().to_vec()));
+        dict.set(
+            "BBox",
+            Object::Array(vec![
+                self.bbox.ll.x.into(),
+                self.bbox.ll.y.into(),
+                self.bbox.ur.x.into(),
+                self.bbox.ur.y.into(),
+            ]),
+        );
+        Object::Stream(Stream::new(dict, self.content))
+    }
+}
+
+/// The icon used to render a [`MarkupAnnotation::Text`] sticky note before it is opened.
+#[derive(Debug, Clone, Copy)]
+pub enum TextIcon {
+    Comment,
+    Key,
+    Note,
+    Help,
+    NewParagraph,
+    Paragraph,
+    Insert,
+}
+
+impl Default for TextIcon {
+    fn default() -> Self {
+        TextIcon::Note
+    }
+}
+
+impl Into<Object> for TextIcon {
+    fn into(self) -> Object {
+        let name = match self {
+            TextIcon::Comment => "Comment",
+            TextIcon::Key => "Key",
+            TextIcon::Note => "Note",
+            TextIcon::Help => "Help",
+            TextIcon::NewParagraph => "NewParagraph",
+            TextIcon::Paragraph => "Paragraph",
+            TextIcon::Insert => "Insert",
+        };
+        Object::Name(name.as_bytes().to_vec())
+    }
+}
+
+/// A `Text` annotation: a sticky-note icon that opens a pop-up window with `common.contents`.
+#[derive(Debug, Clone)]
+pub struct TextAnnotation {
+    pub common: MarkupAnnotationCommon,
+    pub icon: TextIcon,
+    /// Whether the pop-up should initially be displayed open.
+    pub open: bool,
+}
+
+/// A set of four-corner quadrilaterals (`/QuadPoints`) spanning the marked text, used by the
+/// `Highlight`, `Underline`, and `StrikeOut` subtypes.
+pub type QuadPoints = Vec<[f32; 8]>;
+
+/// A `Highlight`, `Underline`, or `StrikeOut` annotation marking up a run of text.
+#[derive(Debug, Clone)]
+pub struct QuadAnnotation {
+    pub common: MarkupAnnotationCommon,
+    pub quad_points: QuadPoints,
+}
+
+/// A `Square` or `Circle` annotation drawing a shape inscribed within `common.rect`.
+#[derive(Debug, Clone)]
+pub struct ShapeAnnotation {
+    pub common: MarkupAnnotationCommon,
+    /// The `/IC` interior (fill) color, if the shape should be filled.
+    pub interior_color: Option<ColorArray>,
+    pub border: BorderArray,
+}
+
+/// The PDF markup annotation `Subtype`s printpdf supports alongside `Link`.
+#[derive(Debug, Clone)]
+pub enum MarkupAnnotation {
+    Text(TextAnnotation),
+    Highlight(QuadAnnotation),
+    Underline(QuadAnnotation),
+    StrikeOut(QuadAnnotation),
+    Square(ShapeAnnotation),
+    Circle(ShapeAnnotation),
+}
+
+impl MarkupAnnotation {
+    fn quad_points_object(quad_points: QuadPoints) -> Object {
+        Object::Array(
+            quad_points
+                .into_iter()
+                .flat_map(|quad| quad.into_iter().map(Object::Real).collect::<Vec<_>>())
+                .collect(),
+        )
+    }
+
+    pub fn into_object(self, doc: &mut lopdf::Document) -> Object {
+        let mut dict = Dictionary::new();
+        dict.set("Type", Object::Name("Annot".as_bytes().to_vec()));
+
+        match self {
+            Self::Text(TextAnnotation { common, icon, open }) => {
+                dict.set("Subtype", Object::Name("Text".as_bytes().to_vec()));
+                dict.set::<&str, Object>("Name", icon.into());
+                dict.set("Open", Object::Boolean(open));
+                common.write_into(doc, &mut dict);
+            }
+            Self::Highlight(QuadAnnotation {
+                common,
+                quad_points,
+            }) => {
+                dict.set("Subtype", Object::Name("Highlight".as_bytes().to_vec()));
+                dict.set("QuadPoints", Self::quad_points_object(quad_points));
+                common.write_into(doc, &mut dict);
+            }
+            Self::Underline(QuadAnnotation {
+                common,
+                quad_points,
+            }) => {
+                dict.set("Subtype", Object::Name("Underline".as_bytes().to_vec()));
+                dict.set("QuadPoints", Self::quad_points_object(quad_points));
+                common.write_into(doc, &mut dict);
+            }
+            Self::StrikeOut(QuadAnnotation {
+                common,
+                quad_points,
+            }) => {
+                dict.set("Subtype", Object::Name("StrikeOut".as_bytes().to_vec()));
+                dict.set("QuadPoints", Self::quad_points_object(quad_points));
+                common.write_into(doc, &mut dict);
+            }
+            Self::Square(ShapeAnnotation {
+                common,
+                interior_color,
+                border,
+            }) => {
+                dict.set("Subtype", Object::Name("Square".as_bytes().to_vec()));
+                if let Some(interior_color) = interior_color {
+                    dict.set::<&str, Object>("IC", interior_color.into());
+                }
+                dict.set("BS", Object::Dictionary(border.into_border_style_dict()));
+                common.write_into(doc, &mut dict);
+            }
+            Self::Circle(ShapeAnnotation {
+                common,
+                interior_color,
+                border,
+            }) => {
+                dict.set("Subtype", Object::Name("Circle".as_bytes().to_vec()));
+                if let Some(interior_color) = interior_color {
+                    dict.set::<&str, Object>("IC", interior_color.into());
+                }
+                dict.set("BS", Object::Dictionary(border.into_border_style_dict()));
+                common.write_into(doc, &mut dict);
+            }
+        }
+
+        Object::Dictionary(dict)
+    }
+}
+
+/// Any annotation kind that can appear in a page's `/Annots` array.
+#[derive(Debug, Clone)]
+pub enum Annotation {
+    Link(LinkAnnotation),
+    Markup(MarkupAnnotation),
+}
+
+impl Annotation {
+    fn into_object(self, doc: &mut lopdf::Document, ctx: AnnotationContext) -> Object {
+        match self {
+            Self::Link(link_annotation) => link_annotation.into_object(ctx),
+            Self::Markup(markup_annotation) => markup_annotation.into_object(doc),
+        }
+    }
+}
+
+/// A per-page collection of [`Annotation`]s, covering both `Link` and the markup subtypes.
+#[derive(Default, Debug, Clone)]
+pub struct AnnotationList {
+    annotations: Vec<Annotation>,
+}
+
+impl AnnotationList {
+    pub fn new() -> Self {
+        Self {
+            annotations: Vec::new(),
+        }
+    }
+
+    pub fn add_link_annotation(&mut self, link_annotation: LinkAnnotation) {
+        self.annotations.push(Annotation::Link(link_annotation));
+    }
+
+    pub fn add_markup_annotation(&mut self, markup_annotation: MarkupAnnotation) {
+        self.annotations.push(Annotation::Markup(markup_annotation));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.annotations.is_empty()
+    }
+
+    /// Writes each annotation in this list into `doc` as its own indirect object and returns the
+    /// resulting `/Annots` array of references, ready to be set on the owning page.
+    pub fn into_annots_array(self, doc: &mut lopdf::Document, ctx: AnnotationContext) -> Object {
+        let refs = self
+            .annotations
+            .into_iter()
+            .map(|annotation| {
+                let object = annotation.into_object(doc, ctx.clone());
+                Object::Reference(doc.add_object(object))
+            })
+            .collect();
+        Object::Array(refs)
+    }
+}